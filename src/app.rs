@@ -1,6 +1,11 @@
 use crate::{
     action::Action,
+    bookmarks::Bookmarks,
     components::Component,
+    entries::Entry,
+    fs_watch::DirectoryWatcher,
+    remote::RemoteControl,
+    repository::{self, LocalRepository, ScriptSource},
     screen::{Mode, Screen},
     tui,
 };
@@ -8,6 +13,7 @@ use color_eyre::eyre;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::prelude::Rect;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use tokio::sync::mpsc;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,6 +23,12 @@ pub enum MessageType {
     Info,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BookmarkPrompt {
+    Set,
+    Jump,
+}
+
 pub struct App {
     pub current_screen: Mode,
     pub exit: bool,
@@ -25,10 +37,30 @@ pub struct App {
     pub frame_rate: f64,
     pub screens: Vec<Screen>,
     pub config: HashMap<String, String>,
+    fs_watcher: DirectoryWatcher,
+    remote: Option<RemoteControl>,
+    selected_mirror: Vec<Entry>,
+    filtering: bool,
+    filter_query: String,
+    bookmarks: Bookmarks,
+    bookmark_prompt: Option<BookmarkPrompt>,
+    repository: Box<dyn ScriptSource>,
 }
 
 impl App {
     pub fn new(screens: Vec<Screen>, config: HashMap<String, String>) -> Self {
+        let bookmarks = Bookmarks::config_path()
+            .and_then(|path| Bookmarks::load(&path).ok())
+            .unwrap_or_default();
+
+        let repository = repository::build(&config).unwrap_or_else(|e| {
+            log::warn!("Failed to build configured script source ({e}), falling back to cwd");
+            Box::new(
+                LocalRepository::new(PathBuf::from("."))
+                    .expect("current working directory always exists"),
+            )
+        });
+
         Self {
             current_screen: Mode::FileChooser,
             exit: false,
@@ -37,6 +69,22 @@ impl App {
             tick_rate: 1.0,
             screens,
             config,
+            fs_watcher: DirectoryWatcher::new(),
+            remote: None,
+            selected_mirror: vec![],
+            filtering: false,
+            filter_query: String::new(),
+            bookmarks,
+            bookmark_prompt: None,
+            repository,
+        }
+    }
+
+    fn save_bookmarks(&self) {
+        if let Some(path) = Bookmarks::config_path() {
+            if let Err(e) = self.bookmarks.save(&path) {
+                log::warn!("Failed to save bookmarks: {e}");
+            }
         }
     }
 
@@ -67,6 +115,20 @@ impl App {
             }
         }
 
+        if let Some(root) = self.repository.current_path() {
+            if let Err(e) = self.fs_watcher.watch(&root, action_tx.clone()) {
+                log::warn!("Failed to watch {}: {e}", root.display());
+            }
+        }
+
+        match RemoteControl::init() {
+            Ok(remote) => {
+                remote.spawn_reader(action_tx.clone());
+                self.remote = Some(remote);
+            }
+            Err(e) => log::warn!("Failed to start remote control FIFO: {e}"),
+        }
+
         loop {
             if let Some(e) = tui.next().await {
                 match e {
@@ -75,6 +137,41 @@ impl App {
                     tui::Event::Render => action_tx.send(Action::Render)?,
                     tui::Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
                     tui::Event::SwitchMode(mode) => action_tx.send(Action::SwitchMode(mode))?,
+                    tui::Event::Key(key) if self.filtering => match key.code {
+                        KeyCode::Esc => {
+                            self.filtering = false;
+                            self.filter_query.clear();
+                            action_tx.send(Action::SetFilter(self.filter_query.clone()))?
+                        }
+                        KeyCode::Enter => self.filtering = false,
+                        KeyCode::Backspace => {
+                            self.filter_query.pop();
+                            action_tx.send(Action::SetFilter(self.filter_query.clone()))?
+                        }
+                        KeyCode::Char(c) => {
+                            self.filter_query.push(c);
+                            action_tx.send(Action::SetFilter(self.filter_query.clone()))?
+                        }
+                        _ => {}
+                    },
+                    tui::Event::Key(key) if self.bookmark_prompt.is_some() => match key.code {
+                        KeyCode::Char(c) => {
+                            match self.bookmark_prompt.take() {
+                                Some(BookmarkPrompt::Set) => {
+                                    action_tx.send(Action::BookmarkAdd(c))?
+                                }
+                                Some(BookmarkPrompt::Jump) => {
+                                    action_tx.send(Action::BookmarkJump(c))?
+                                }
+                                None => {}
+                            }
+                            action_tx.send(Action::HideBookmarks)?
+                        }
+                        _ => {
+                            self.bookmark_prompt = None;
+                            action_tx.send(Action::HideBookmarks)?
+                        }
+                    },
                     tui::Event::Key(key) => match (self.current_screen, key.code) {
                         (_, KeyCode::Char('z')) if key.modifiers == KeyModifiers::CONTROL => {
                             action_tx.send(Action::Suspend)?
@@ -91,10 +188,24 @@ impl App {
                             action_tx.send(Action::RemoveAllSelectedScripts)?
                         }
                         (_, KeyCode::Char('x')) => action_tx.send(Action::RemoveSelectedScript)?,
+                        (_, KeyCode::Char('/')) => {
+                            self.filtering = true;
+                            self.filter_query.clear();
+                        }
+                        (_, KeyCode::Char('m')) => {
+                            self.bookmark_prompt = Some(BookmarkPrompt::Set);
+                            action_tx.send(Action::ShowBookmarks(self.bookmark_list()))?
+                        }
+                        (_, KeyCode::Char('\'')) => {
+                            self.bookmark_prompt = Some(BookmarkPrompt::Jump);
+                            action_tx.send(Action::ShowBookmarks(self.bookmark_list()))?
+                        }
                         (_, KeyCode::Up) => action_tx.send(Action::CursorUp)?,
                         (_, KeyCode::Down) => action_tx.send(Action::CursorDown)?,
                         (_, KeyCode::Home) => action_tx.send(Action::CursorToTop)?,
                         (_, KeyCode::End) => action_tx.send(Action::CursorToBottom)?,
+                        (_, KeyCode::PageUp) => action_tx.send(Action::PreviewScrollUp)?,
+                        (_, KeyCode::PageDown) => action_tx.send(Action::PreviewScrollDown)?,
                         (_, KeyCode::Enter) => action_tx.send(Action::DirectoryOpenSelected)?,
                         (_, KeyCode::Backspace) => action_tx.send(Action::DirectoryLeave)?,
                         (Mode::FileChooser, KeyCode::Tab) => {
@@ -129,6 +240,82 @@ impl App {
                     Action::Suspend => self.suspend = true,
                     Action::Resume => self.suspend = false,
                     Action::SwitchMode(mode) => self.current_screen = mode,
+                    Action::DirectoryChanged(ref path) => {
+                        if let Err(e) = self.fs_watcher.watch(path, action_tx.clone()) {
+                            log::warn!("Failed to watch {}: {e}", path.display());
+                        }
+                    }
+                    Action::DirectoryEnter(name) => {
+                        self.repository.open_directory(&name);
+                        if let Some(path) = self.repository.current_path() {
+                            action_tx.send(Action::DirectoryChanged(path))?;
+                        }
+                        action_tx.send(Action::RefreshDirectory)?;
+                    }
+                    Action::DirectoryLeave => {
+                        self.repository.leave_directory();
+                        if let Some(path) = self.repository.current_path() {
+                            action_tx.send(Action::DirectoryChanged(path))?;
+                        }
+                        action_tx.send(Action::RefreshDirectory)?;
+                    }
+                    Action::RefreshDirectory => {
+                        let entries = self.repository.read_entries_in_current_directory();
+                        action_tx.send(Action::DirectoryListed(entries))?;
+                    }
+                    Action::PreviewEntry(ref entry) => {
+                        if let Some(remote) = &self.remote {
+                            if let Ok(path) = entry.get_full_path() {
+                                if let Some(path) = path.to_str() {
+                                    let _ = remote.write_focus(path);
+                                }
+                            }
+                        }
+                    }
+                    Action::SelectScripts(ref entries) => {
+                        self.selected_mirror = entries.clone();
+                        self.write_selection();
+                    }
+                    Action::AppendScripts(ref entries) => {
+                        let mut only_new: Vec<Entry> = entries
+                            .iter()
+                            .filter(|e| !self.selected_mirror.contains(e))
+                            .cloned()
+                            .collect();
+                        self.selected_mirror.append(&mut only_new);
+                        self.write_selection();
+                    }
+                    Action::RemoveScript(ref entry) => {
+                        self.selected_mirror.retain(|e| e != entry);
+                        self.write_selection();
+                    }
+                    Action::RemoveAllSelectedScripts => {
+                        self.selected_mirror.clear();
+                        self.write_selection();
+                    }
+                    Action::BookmarkAdd(key) => {
+                        if let Some(path) = self.repository.current_path() {
+                            self.bookmarks.set(key, path);
+                            self.save_bookmarks();
+                        }
+                    }
+                    Action::BookmarkJump(key) => match self.bookmarks.get(key).cloned() {
+                        Some(path) if path.exists() => match LocalRepository::new(path.clone()) {
+                            Ok(repo) => {
+                                self.repository = Box::new(repo);
+                                action_tx.send(Action::DirectoryChanged(path))?;
+                                action_tx.send(Action::RefreshDirectory)?;
+                            }
+                            Err(e) => action_tx.send(Action::Error(format!(
+                                "Failed to jump to bookmark '{key}': {e}"
+                            )))?,
+                        },
+                        Some(_) => action_tx.send(Action::Error(format!(
+                            "Bookmark '{key}' no longer exists"
+                        )))?,
+                        None => action_tx
+                            .send(Action::Error(format!("No bookmark set for '{key}'")))?,
+                    },
                     Action::Resize(w, h) => {
                         tui.resize(Rect::new(0, 0, w, h))?;
                         let screen = self
@@ -204,4 +391,17 @@ impl App {
         tui.exit()?;
         Ok(())
     }
+
+    fn write_selection(&self) {
+        if let Some(remote) = &self.remote {
+            let _ = remote.write_selection(&self.selected_mirror);
+        }
+    }
+
+    fn bookmark_list(&self) -> Vec<(char, String)> {
+        self.bookmarks
+            .iter()
+            .map(|(key, path)| (key, path.display().to_string()))
+            .collect()
+    }
 }
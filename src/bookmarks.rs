@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+/// Directory bookmarks keyed by a single letter, persisted to a TOML file
+/// in the config dir so marks survive between sessions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Bookmarks {
+    marks: HashMap<String, PathBuf>,
+}
+
+impl Bookmarks {
+    pub fn load(path: &Path) -> eyre::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let contents = toml::to_string_pretty(self)?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    pub fn set(&mut self, key: char, path: PathBuf) {
+        self.marks.insert(key.to_string(), path);
+    }
+
+    pub fn get(&self, key: char) -> Option<&PathBuf> {
+        self.marks.get(&key.to_string())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (char, &PathBuf)> {
+        self.marks
+            .iter()
+            .filter_map(|(k, v)| k.chars().next().map(|c| (c, v)))
+    }
+
+    pub fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| {
+            dir.join("aequitas-command-and-control")
+                .join("bookmarks.toml")
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_and_get_round_trip_in_memory() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/some/path"));
+
+        assert_eq!(bookmarks.get('a'), Some(&PathBuf::from("/some/path")));
+        assert_eq!(bookmarks.get('b'), None);
+    }
+
+    #[test]
+    fn set_overwrites_existing_key() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/first"));
+        bookmarks.set('a', PathBuf::from("/second"));
+
+        assert_eq!(bookmarks.get('a'), Some(&PathBuf::from("/second")));
+    }
+
+    #[test]
+    fn iter_yields_all_marks_as_chars() {
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/a"));
+        bookmarks.set('b', PathBuf::from("/b"));
+
+        let mut marks: Vec<(char, PathBuf)> = bookmarks
+            .iter()
+            .map(|(k, v)| (k, v.clone()))
+            .collect();
+        marks.sort();
+
+        assert_eq!(
+            marks,
+            vec![('a', PathBuf::from("/a")), ('b', PathBuf::from("/b"))]
+        );
+    }
+
+    #[test]
+    fn save_then_load_round_trips_through_disk() {
+        let path = std::env::temp_dir().join(format!(
+            "aequitas-cc-bookmarks-test-{}.toml",
+            std::process::id()
+        ));
+
+        let mut bookmarks = Bookmarks::default();
+        bookmarks.set('a', PathBuf::from("/some/path"));
+        bookmarks.save(&path).unwrap();
+
+        let loaded = Bookmarks::load(&path).unwrap();
+        assert_eq!(loaded.get('a'), Some(&PathBuf::from("/some/path")));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_missing_file_returns_default() {
+        let path = PathBuf::from("/nonexistent/aequitas-cc-bookmarks.toml");
+        let loaded = Bookmarks::load(&path).unwrap();
+
+        assert_eq!(loaded.get('a'), None);
+    }
+}
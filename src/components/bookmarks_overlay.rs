@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{Ok, Result};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear, List},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::Component;
+use crate::{action::Action, tui::Frame};
+
+/// Popup listing the current directory bookmarks, shown while the user is
+/// choosing a letter to set or jump to.
+pub struct BookmarksOverlay {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: HashMap<String, String>,
+    visible: bool,
+    marks: Vec<(char, String)>,
+}
+
+impl BookmarksOverlay {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: HashMap::<String, String>::default(),
+            visible: false,
+            marks: vec![],
+        }
+    }
+}
+
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    }
+}
+
+impl Component for BookmarksOverlay {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: HashMap<String, String>) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::ShowBookmarks(marks) => {
+                self.marks = marks;
+                self.visible = true;
+            }
+            Action::HideBookmarks => self.visible = false,
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+
+        let items: Vec<String> = if self.marks.is_empty() {
+            vec!["(no bookmarks)".into()]
+        } else {
+            self.marks
+                .iter()
+                .map(|(key, path)| format!("{key}  {path}"))
+                .collect()
+        };
+
+        let popup = centered(area, 50, (items.len() as u16 + 2).max(3));
+        f.render_widget(Clear, popup);
+
+        let list = List::new(items).block(
+            Block::default()
+                .title("Bookmarks")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double),
+        );
+
+        f.render_widget(list, popup);
+
+        Ok(())
+    }
+}
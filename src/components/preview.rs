@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use color_eyre::eyre::{Ok, Result};
+use ratatui::{
+    prelude::*,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color as SynColor, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::Component;
+use crate::{action::Action, entries::Entry, tui::Frame};
+
+/// Cap on how many lines of a file we'll highlight and keep in memory.
+const MAX_PREVIEW_LINES: usize = 500;
+
+fn to_ratatui_color(color: SynColor) -> Color {
+    Color::Rgb(color.r, color.g, color.b)
+}
+
+pub struct Preview {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: HashMap<String, String>,
+    syntax_set: SyntaxSet,
+    theme_set: ThemeSet,
+    path: Option<PathBuf>,
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+    cache: HashMap<PathBuf, Vec<Line<'static>>>,
+}
+
+impl Preview {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: HashMap::<String, String>::default(),
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            theme_set: ThemeSet::load_defaults(),
+            path: None,
+            lines: vec![],
+            scroll: 0,
+            cache: HashMap::new(),
+        }
+    }
+
+    fn highlight(&self, lines: impl Iterator<Item = String>) -> Vec<Line<'static>> {
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension("sql")
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let theme = &self.theme_set.themes["base16-ocean.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        lines
+            .take(MAX_PREVIEW_LINES)
+            .filter_map(|line| highlighter.highlight_line(&line, &self.syntax_set).ok())
+            .map(|ranges| {
+                let spans: Vec<Span<'static>> = ranges
+                    .into_iter()
+                    .map(|(style, text)| {
+                        Span::styled(
+                            text.to_string(),
+                            Style::default().fg(to_ratatui_color(style.foreground)),
+                        )
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    fn load(&mut self, entry: &Entry) {
+        let path = match entry.get_full_path() {
+            std::result::Result::Ok(path) => path,
+            std::result::Result::Err(_) => return,
+        };
+
+        if let Some(cached) = self.cache.get(&path) {
+            self.lines = cached.clone();
+            self.path = Some(path);
+            self.scroll = 0;
+            return;
+        }
+
+        // Only the first MAX_PREVIEW_LINES lines are ever read off disk,
+        // so previewing a huge migration doesn't pull the whole file in.
+        let lines = match File::open(&path) {
+            std::result::Result::Ok(file) => {
+                // BufRead::lines() strips the trailing '\n', but the
+                // newline-aware syntax set needs it back to track
+                // multi-line constructs (e.g. block comments) correctly.
+                let line_iter = BufReader::new(file)
+                    .lines()
+                    .take(MAX_PREVIEW_LINES)
+                    .map_while(std::result::Result::ok)
+                    .map(|mut line| {
+                        line.push('\n');
+                        line
+                    });
+                self.highlight(line_iter)
+            }
+            std::result::Result::Err(_) => vec![],
+        };
+
+        self.cache.insert(path.clone(), lines.clone());
+        self.lines = lines;
+        self.path = Some(path);
+        self.scroll = 0;
+    }
+
+    fn scroll_by(&mut self, delta: i16) {
+        let max = self.lines.len().saturating_sub(1) as u16;
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(delta)
+            .min(max);
+    }
+}
+
+impl Component for Preview {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: HashMap<String, String>) -> Result<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::PreviewEntry(entry) => {
+                self.load(&entry);
+                return Ok(None);
+            }
+            Action::CursorUp | Action::CursorDown | Action::CursorToTop | Action::CursorToBottom => {
+                self.scroll = 0;
+                return Ok(None);
+            }
+            Action::PreviewScrollUp => {
+                self.scroll_by(-1);
+                return Ok(None);
+            }
+            Action::PreviewScrollDown => {
+                self.scroll_by(1);
+                return Ok(None);
+            }
+            Action::RefreshDirectory => {
+                // A filesystem change may have touched the previewed file
+                // itself, so cached highlighted lines can't be trusted
+                // across a refresh.
+                self.cache.clear();
+                return Ok(None);
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
+        let title = self
+            .path
+            .as_ref()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("Preview");
+
+        let paragraph = Paragraph::new(self.lines.clone())
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double),
+            )
+            .scroll((self.scroll, 0));
+
+        f.render_widget(paragraph, area);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn highlight_produces_one_line_per_input_line() {
+        let preview = Preview::new();
+        let lines = preview.highlight(
+            vec!["SELECT 1;\n".to_string(), "SELECT 2;\n".to_string()].into_iter(),
+        );
+
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn highlight_caps_at_max_preview_lines() {
+        let preview = Preview::new();
+        let input = std::iter::repeat("SELECT 1;\n".to_string()).take(MAX_PREVIEW_LINES + 10);
+        let lines = preview.highlight(input);
+
+        assert_eq!(lines.len(), MAX_PREVIEW_LINES);
+    }
+
+    #[test]
+    fn scroll_by_does_not_underflow_on_empty_file() {
+        let mut preview = Preview::new();
+        preview.scroll_by(-1);
+
+        assert_eq!(preview.scroll, 0);
+    }
+
+    #[test]
+    fn scroll_by_clamps_to_last_line() {
+        let mut preview = Preview::new();
+        preview.lines = preview.highlight(
+            vec!["a\n".to_string(), "b\n".to_string(), "c\n".to_string()].into_iter(),
+        );
+
+        preview.scroll_by(10);
+        assert_eq!(preview.scroll, 2);
+
+        preview.scroll_by(-1);
+        assert_eq!(preview.scroll, 1);
+    }
+
+    #[test]
+    fn cache_is_cleared_on_refresh_directory() {
+        let mut preview = Preview::new();
+        preview
+            .cache
+            .insert(PathBuf::from("/tmp/whatever.sql"), vec![]);
+
+        preview.update(Action::RefreshDirectory).unwrap();
+
+        assert!(preview.cache.is_empty());
+    }
+}
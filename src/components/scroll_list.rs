@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use color_eyre::eyre::{Ok, Result};
+use glob::Pattern;
 use ratatui::{
     prelude::*,
     widgets::{Block, BorderType, Borders, List, ListState},
@@ -14,11 +15,16 @@ use crate::{
     tui::Frame,
 };
 
+/// Prefix that switches the filter query from plain substring matching
+/// into glob matching, e.g. `glob:*_2024*.sql`.
+const GLOB_PREFIX: &str = "glob:";
+
 pub struct ScrollList {
     command_tx: Option<UnboundedSender<Action>>,
     config: HashMap<String, String>,
     state: ListState,
     entries: Vec<Entry>,
+    filter: String,
 }
 
 impl ScrollList {
@@ -28,6 +34,7 @@ impl ScrollList {
             config: HashMap::<String, String>::default(),
             state: ListState::default().with_selected(Some(0)),
             entries: vec![],
+            filter: String::new(),
         }
     }
 
@@ -40,6 +47,9 @@ impl ScrollList {
     }
 
     pub fn cursor_down(&mut self, entries_len: usize) {
+        if entries_len == 0 {
+            return;
+        }
         if let Some(position) = self.state.selected() {
             if position < entries_len - 1 {
                 self.state.select(Some(position + 1))
@@ -52,8 +62,45 @@ impl ScrollList {
     }
 
     pub fn go_to_bottom(&mut self, entries_len: usize) {
+        if entries_len == 0 {
+            return;
+        }
         self.state.select(Some(entries_len - 1));
     }
+
+    /// The entries currently visible under the active filter, or all
+    /// entries when no filter is set.
+    fn visible_entries(&self) -> Vec<&Entry> {
+        if self.filter.is_empty() {
+            return self.entries.iter().collect();
+        }
+
+        if let Some(pattern) = self.filter.strip_prefix(GLOB_PREFIX) {
+            let pattern = Pattern::new(pattern).ok();
+            return self
+                .entries
+                .iter()
+                .filter(|e| {
+                    pattern
+                        .as_ref()
+                        .map(|p| p.matches(e.get_relative_path()))
+                        .unwrap_or(false)
+                })
+                .collect();
+        }
+
+        let query = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|e| e.get_relative_path().to_lowercase().contains(&query))
+            .collect()
+    }
+
+    fn current_entry(&self) -> Option<Entry> {
+        self.state
+            .selected()
+            .and_then(|pos| self.visible_entries().get(pos).copied().cloned())
+    }
 }
 
 impl Component for ScrollList {
@@ -72,28 +119,37 @@ impl Component for ScrollList {
             Action::Tick => {}
             Action::CursorUp => {
                 self.cursor_up();
-                return Ok(None);
+                return Ok(self.current_entry().map(Action::PreviewEntry));
             }
             Action::CursorDown => {
-                self.cursor_down(self.entries.len());
-                return Ok(None);
+                self.cursor_down(self.visible_entries().len());
+                return Ok(self.current_entry().map(Action::PreviewEntry));
             }
             Action::CursorToTop => {
                 self.go_to_top();
-                return Ok(None);
+                return Ok(self.current_entry().map(Action::PreviewEntry));
             }
             Action::CursorToBottom => {
-                self.go_to_bottom(self.entries.len());
-                return Ok(None);
+                self.go_to_bottom(self.visible_entries().len());
+                return Ok(self.current_entry().map(Action::PreviewEntry));
             }
             Action::RemoveSelectedScript => {
-                if let Some(pos) = self.state.selected() {
-                    let entry = self.entries.get(pos);
-                    if let Some(entry) = entry {
-                        return Ok(Some(Action::RemoveScript(entry.clone())));
+                if let Some(entry) = self.current_entry() {
+                    return Ok(Some(Action::RemoveScript(entry)));
+                }
+            }
+            Action::DirectoryOpenSelected => {
+                if let Some(entry) = self.current_entry() {
+                    if entry.is_directory() {
+                        return Ok(Some(Action::DirectoryEnter(entry.name().to_string())));
                     }
                 }
             }
+            Action::SetFilter(query) => {
+                self.filter = query;
+                self.state.select(Some(0));
+                return Ok(self.current_entry().map(Action::PreviewEntry));
+            }
             _ => {}
         }
         Ok(None)
@@ -125,8 +181,8 @@ impl Component for ScrollList {
 
     fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()> {
         let items: Vec<String> = self
-            .entries
-            .iter()
+            .visible_entries()
+            .into_iter()
             .filter_map(|e| e.get_full_path().ok()?.to_str().map(str::to_owned))
             .map(String::from)
             .collect();
@@ -146,3 +202,42 @@ impl Component for ScrollList {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cursor_down_does_not_underflow_on_empty_list() {
+        let mut list = ScrollList::new();
+        list.cursor_down(0);
+
+        assert_eq!(list.state.selected(), Some(0));
+    }
+
+    #[test]
+    fn go_to_bottom_does_not_underflow_on_empty_list() {
+        let mut list = ScrollList::new();
+        list.go_to_bottom(0);
+
+        assert_eq!(list.state.selected(), Some(0));
+    }
+
+    #[test]
+    fn cursor_down_stops_at_last_entry() {
+        let mut list = ScrollList::new();
+        list.cursor_down(2);
+        assert_eq!(list.state.selected(), Some(1));
+
+        list.cursor_down(2);
+        assert_eq!(list.state.selected(), Some(1));
+    }
+
+    #[test]
+    fn go_to_bottom_selects_last_index() {
+        let mut list = ScrollList::new();
+        list.go_to_bottom(5);
+
+        assert_eq!(list.state.selected(), Some(4));
+    }
+}
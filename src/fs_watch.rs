@@ -0,0 +1,50 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+
+/// Bridges filesystem change notifications for a single directory into the
+/// app's action loop, re-pointing itself whenever the browsed directory
+/// changes.
+pub struct DirectoryWatcher {
+    inner: Option<RecommendedWatcher>,
+    watched: Option<PathBuf>,
+}
+
+impl DirectoryWatcher {
+    pub fn new() -> Self {
+        Self {
+            inner: None,
+            watched: None,
+        }
+    }
+
+    /// Start (or move) the watch to `path`, unwatching whatever directory
+    /// was previously watched. Every filesystem event under `path` is
+    /// forwarded as `Action::RefreshDirectory`.
+    pub fn watch(&mut self, path: &Path, tx: UnboundedSender<Action>) -> eyre::Result<()> {
+        if let (Some(watcher), Some(old)) = (self.inner.as_mut(), self.watched.as_ref()) {
+            let _ = watcher.unwatch(old);
+        }
+
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() {
+                let _ = tx.send(Action::RefreshDirectory);
+            }
+        })?;
+        watcher.watch(path, RecursiveMode::NonRecursive)?;
+
+        self.inner = Some(watcher);
+        self.watched = Some(path.to_path_buf());
+        Ok(())
+    }
+}
+
+impl Default for DirectoryWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
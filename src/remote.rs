@@ -0,0 +1,165 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre;
+use nix::sys::stat::Mode;
+use nix::unistd::mkfifo;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{action::Action, entries::Entry, screen::Mode as ScreenMode};
+
+const MSG_IN: &str = "msg_in";
+const SELECTION_OUT: &str = "selection_out";
+const FOCUS_OUT: &str = "focus_out";
+
+/// Exposes the action loop to external tools through a named pipe and a
+/// couple of plain files, mirroring xplr's `msg_in`/`focus_out`/
+/// `selection_out` convention so the app can be driven from shell scripts.
+pub struct RemoteControl {
+    session_dir: PathBuf,
+}
+
+impl RemoteControl {
+    /// Creates the session directory (under `$XDG_RUNTIME_DIR`, falling
+    /// back to `/tmp`) along with the `msg_in` FIFO and the plain
+    /// `selection_out`/`focus_out` files.
+    pub fn init() -> eyre::Result<Self> {
+        let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".into());
+        let session_dir =
+            PathBuf::from(runtime_dir).join(format!("aequitas-cc.{}", std::process::id()));
+        fs::create_dir_all(&session_dir)?;
+
+        let msg_in = session_dir.join(MSG_IN);
+        if !msg_in.exists() {
+            mkfifo(&msg_in, Mode::S_IRUSR | Mode::S_IWUSR)?;
+        }
+
+        fs::write(session_dir.join(SELECTION_OUT), "")?;
+        fs::write(session_dir.join(FOCUS_OUT), "")?;
+
+        Ok(Self { session_dir })
+    }
+
+    pub fn session_dir(&self) -> &Path {
+        &self.session_dir
+    }
+
+    /// Spawns the background task that reads newline-delimited commands
+    /// from `msg_in` and forwards the parsed actions onto `tx`.
+    pub fn spawn_reader(&self, tx: UnboundedSender<Action>) {
+        let msg_in = self.session_dir.join(MSG_IN);
+        tokio::spawn(async move {
+            loop {
+                let file = match tokio::fs::File::open(&msg_in).await {
+                    Ok(file) => file,
+                    Err(_) => {
+                        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                        continue;
+                    }
+                };
+
+                let mut lines = BufReader::new(file).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if let Some(action) = parse_command(&line) {
+                        let _ = tx.send(action);
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn write_focus(&self, path: &str) -> eyre::Result<()> {
+        atomic_write(&self.session_dir.join(FOCUS_OUT), path)
+    }
+
+    pub fn write_selection(&self, entries: &[Entry]) -> eyre::Result<()> {
+        let body = entries
+            .iter()
+            .filter_map(|e| e.get_full_path().ok())
+            .filter_map(|p| p.to_str().map(str::to_owned))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        atomic_write(&self.session_dir.join(SELECTION_OUT), &body)
+    }
+}
+
+fn atomic_write(path: &Path, contents: &str) -> eyre::Result<()> {
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn parse_command(line: &str) -> Option<Action> {
+    let line = line.trim();
+    let (command, arg) = match line.split_once(' ') {
+        Some((command, arg)) => (command, Some(arg.trim())),
+        None => (line, None),
+    };
+
+    match command {
+        "select_current" => Some(Action::SelectCurrent),
+        "select_all_after" => Some(Action::SelectAllAfter),
+        "select_all_in_directory" => Some(Action::SelectAllInDirectory),
+        "cursor_up" => Some(Action::CursorUp),
+        "cursor_down" => Some(Action::CursorDown),
+        "cursor_to_top" => Some(Action::CursorToTop),
+        "cursor_to_bottom" => Some(Action::CursorToBottom),
+        "directory_open_selected" => Some(Action::DirectoryOpenSelected),
+        "directory_leave" => Some(Action::DirectoryLeave),
+        "script_run" => Some(Action::ScriptRun),
+        "switch_mode" => match arg {
+            Some("file_chooser") => Some(Action::SwitchMode(ScreenMode::FileChooser)),
+            Some("script_runner") => Some(Action::SwitchMode(ScreenMode::ScriptRunner)),
+            _ => None,
+        },
+        "quit" => Some(Action::Quit),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_plain_commands() {
+        assert_eq!(parse_command("cursor_down"), Some(Action::CursorDown));
+        assert_eq!(parse_command("quit"), Some(Action::Quit));
+        assert_eq!(
+            parse_command("directory_open_selected"),
+            Some(Action::DirectoryOpenSelected)
+        );
+    }
+
+    #[test]
+    fn parses_command_with_argument() {
+        assert_eq!(
+            parse_command("switch_mode file_chooser"),
+            Some(Action::SwitchMode(ScreenMode::FileChooser))
+        );
+        assert_eq!(
+            parse_command("switch_mode script_runner"),
+            Some(Action::SwitchMode(ScreenMode::ScriptRunner))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_command_and_argument() {
+        assert_eq!(parse_command("not_a_real_command"), None);
+        assert_eq!(parse_command("switch_mode not_a_mode"), None);
+    }
+
+    #[test]
+    fn trims_surrounding_whitespace() {
+        assert_eq!(parse_command("  cursor_up  "), Some(Action::CursorUp));
+    }
+
+    #[test]
+    fn empty_line_is_not_a_command() {
+        assert_eq!(parse_command(""), None);
+        assert_eq!(parse_command("   "), None);
+    }
+}
@@ -1,9 +1,11 @@
-use std::{fs::read_dir, path::PathBuf};
+use std::{fmt, fs::read_dir, path::PathBuf};
 
 use color_eyre::eyre;
 
 use crate::entries::ListEntry;
 
+use super::ScriptSource;
+
 #[derive(Debug)]
 pub enum RepositoryError {
     DoesNotExist,
@@ -11,23 +13,35 @@ pub enum RepositoryError {
     NotUTF8,
 }
 
-pub struct Repository {
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryError::DoesNotExist => write!(f, "path does not exist"),
+            RepositoryError::IOError(e) => write!(f, "io error: {e}"),
+            RepositoryError::NotUTF8 => write!(f, "path is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for RepositoryError {}
+
+pub struct LocalRepository {
     root: PathBuf,
     root_str: String,
     path: Vec<String>,
 }
 
-impl Repository {
+impl LocalRepository {
     /// Attempts to store path, if it's valid and the file exists.
     /// Used for longer storage of paths.
     ///
     /// # Examples
     ///
     /// ```
-    /// let x: Result<Repository, RepositoryError> = Repository::new("some/existing/file");
+    /// let x: Result<LocalRepository, RepositoryError> = LocalRepository::new("some/existing/file".into());
     /// assert_eq!(x.is_ok(), true);
     ///
-    /// let x: Result<Repository, RepositoryError> = Repository::new("some/non-existing/file");
+    /// let x: Result<LocalRepository, RepositoryError> = LocalRepository::new("some/non-existing/file".into());
     /// assert_eq!(x.is_ok(), false);
     /// ```
     pub fn new(root: PathBuf) -> Result<Self, RepositoryError> {
@@ -74,14 +88,6 @@ impl Repository {
         c.to_str().unwrap().replace(&b, "")
     }
 
-    pub fn open_directory(&mut self, directory_name: &str) {
-        self.path.push(directory_name.into());
-    }
-
-    pub fn leave_directory(&mut self) -> Option<String> {
-        self.path.pop()
-    }
-
     pub fn read_files_in_directory(&self) -> eyre::Result<Vec<String>> {
         let current = self.current_as_path_buf();
         let base = self.base_as_path_buf();
@@ -109,7 +115,22 @@ impl Repository {
         Ok(entries)
     }
 
-    pub fn get_children(&self, path: String) -> Vec<String> {
+    pub fn read_file(&self, relative_path: &str) -> eyre::Result<String> {
+        let path = self.base_as_path_buf().join(relative_path);
+        Ok(std::fs::read_to_string(path)?)
+    }
+}
+
+impl ScriptSource for LocalRepository {
+    fn open_directory(&mut self, directory_name: &str) {
+        self.path.push(directory_name.into());
+    }
+
+    fn leave_directory(&mut self) -> Option<String> {
+        self.path.pop()
+    }
+
+    fn get_children(&self, path: String) -> Vec<String> {
         let base = self.base_as_path_buf();
         let path = base.join(path);
 
@@ -144,7 +165,7 @@ impl Repository {
         }
     }
 
-    pub fn read_files_after_in_directory(&self, from: &str) -> eyre::Result<Vec<String>> {
+    fn read_files_after_in_directory(&self, from: &str) -> eyre::Result<Vec<String>> {
         let current = self.current_as_path_buf();
         let base = self.base_as_path_buf();
         let entries = read_dir(current)?
@@ -175,7 +196,7 @@ impl Repository {
         Ok(entries)
     }
 
-    pub fn read_entries_in_current_directory(&self) -> Vec<ListEntry> {
+    fn read_entries_in_current_directory(&self) -> Vec<ListEntry> {
         let current = self.current_as_path_buf();
         let base = self.base_as_path_buf();
 
@@ -223,6 +244,14 @@ impl Repository {
 
         entries
     }
+
+    fn read_file(&self, relative_path: &str) -> eyre::Result<String> {
+        LocalRepository::read_file(self, relative_path)
+    }
+
+    fn current_path(&self) -> Option<PathBuf> {
+        Some(self.current_as_path_buf())
+    }
 }
 
 #[cfg(test)]
@@ -233,7 +262,7 @@ mod test {
     #[test]
     fn repository_path_success() {
         let path = ".tests/repository/success";
-        let r = Repository::new(PathBuf::from(path));
+        let r = LocalRepository::new(PathBuf::from(path));
 
         assert_eq!(true, r.is_ok());
         assert_eq!(String::from(path), r.unwrap().base_as_str())
@@ -241,7 +270,7 @@ mod test {
 
     #[test]
     fn repository_path_does_not_exist() {
-        let r = Repository::new(PathBuf::from(".tests/repository/failure"));
+        let r = LocalRepository::new(PathBuf::from(".tests/repository/failure"));
 
         assert!(r.is_err());
         match r {
@@ -256,7 +285,7 @@ mod test {
         let non_utf8_os_string = OsString::from_vec(non_utf8_bytes);
         let non_utf8_path = PathBuf::from(non_utf8_os_string);
 
-        let r = Repository::new(non_utf8_path);
+        let r = LocalRepository::new(non_utf8_path);
 
         assert_eq!(true, r.is_err());
         match r {
@@ -268,7 +297,7 @@ mod test {
     #[test]
     fn repository_path_movement() {
         let path = ".tests/repository/dir1";
-        let r = Repository::new(PathBuf::from(path));
+        let r = LocalRepository::new(PathBuf::from(path));
 
         assert_eq!(true, r.is_ok());
 
@@ -290,7 +319,7 @@ mod test {
     #[test]
     fn repository_path_relative() {
         let path = ".tests/repository/dir1";
-        let r = Repository::new(PathBuf::from(path));
+        let r = LocalRepository::new(PathBuf::from(path));
 
         assert_eq!(true, r.is_ok());
 
@@ -304,7 +333,7 @@ mod test {
     // #[test]
     // fn repository_path_files() {
     //     let path = ".tests/repository/dir1";
-    //     let r = Repository::new(PathBuf::from(path));
+    //     let r = LocalRepository::new(PathBuf::from(path));
 
     //     assert_eq!(true, r.is_ok());
 
@@ -0,0 +1,46 @@
+mod local;
+mod sftp;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use color_eyre::eyre;
+
+pub use local::{LocalRepository, RepositoryError};
+pub use sftp::SftpRepository;
+
+use crate::entries::ListEntry;
+
+/// Directory-listing/reading surface shared by every place scripts can be
+/// browsed from, whether that's the local filesystem or a remote server.
+pub trait ScriptSource {
+    fn read_entries_in_current_directory(&self) -> Vec<ListEntry>;
+    fn get_children(&self, path: String) -> Vec<String>;
+    fn open_directory(&mut self, directory_name: &str);
+    fn leave_directory(&mut self) -> Option<String>;
+    fn read_files_after_in_directory(&self, from: &str) -> eyre::Result<Vec<String>>;
+    fn read_file(&self, relative_path: &str) -> eyre::Result<String>;
+
+    /// The on-disk directory currently being browsed, if the source is
+    /// backed by the local filesystem. Remote sources have nothing
+    /// meaningful to return here (there's no local path to watch).
+    fn current_path(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// Builds the configured `ScriptSource`, picking between `LocalRepository`
+/// and `SftpRepository` based on the `source` config key (`local` by
+/// default).
+pub fn build(config: &HashMap<String, String>) -> eyre::Result<Box<dyn ScriptSource>> {
+    match config.get("source").map(String::as_str) {
+        Some("sftp") => Ok(Box::new(SftpRepository::new(config)?)),
+        _ => {
+            let root = config
+                .get("root")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("."));
+            Ok(Box::new(LocalRepository::new(root)?))
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::TcpStream;
+use std::path::Path;
+
+use color_eyre::eyre::{self, eyre};
+use ssh2::{Session, Sftp};
+
+use crate::entries::ListEntry;
+
+use super::ScriptSource;
+
+/// Browses and reads `.sql` scripts from a remote server over SFTP,
+/// authenticated with either a private key or a password.
+pub struct SftpRepository {
+    session: Session,
+    base_path: String,
+    path: Vec<String>,
+}
+
+impl SftpRepository {
+    pub fn new(config: &HashMap<String, String>) -> eyre::Result<Self> {
+        let host = config
+            .get("host")
+            .ok_or_else(|| eyre!("sftp source requires a 'host' config value"))?;
+        let port: u16 = config
+            .get("port")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(22);
+        let user = config
+            .get("user")
+            .ok_or_else(|| eyre!("sftp source requires a 'user' config value"))?;
+        let base_path = config
+            .get("base_path")
+            .cloned()
+            .unwrap_or_else(|| "/".into());
+
+        let tcp = TcpStream::connect((host.as_str(), port))?;
+        let mut session = Session::new()?;
+        session.set_tcp_stream(tcp);
+        session.handshake()?;
+
+        if let Some(key_path) = config.get("key") {
+            session.userauth_pubkey_file(user, None, Path::new(key_path), None)?;
+        } else if let Some(password) = config.get("password") {
+            session.userauth_password(user, password)?;
+        } else {
+            return Err(eyre!(
+                "sftp source requires either a 'key' or a 'password' config value"
+            ));
+        }
+
+        if !session.authenticated() {
+            return Err(eyre!("sftp authentication failed for {user}@{host}"));
+        }
+
+        Ok(Self {
+            session,
+            base_path,
+            path: vec![],
+        })
+    }
+
+    fn current_remote_path(&self) -> String {
+        self.path
+            .iter()
+            .fold(self.base_path.clone(), |acc, part| format!("{acc}/{part}"))
+    }
+
+    fn sftp(&self) -> eyre::Result<Sftp> {
+        Ok(self.session.sftp()?)
+    }
+
+    fn list(&self, remote_dir: &str) -> Vec<ListEntry> {
+        let Ok(sftp) = self.sftp() else {
+            return vec![];
+        };
+        let Ok(entries) = sftp.readdir(Path::new(remote_dir)) else {
+            return vec![];
+        };
+
+        let mut entries: Vec<ListEntry> = entries
+            .into_iter()
+            .filter_map(|(path, stat)| {
+                let file_name = path.file_name()?.to_str()?;
+                if file_name.starts_with('_') || file_name.starts_with('.') {
+                    return None;
+                }
+
+                let relative_path = strip_base_path(path.to_str()?, &self.base_path);
+
+                if stat.is_dir() {
+                    Some(ListEntry {
+                        is_directory: true,
+                        relative_path,
+                        name: file_name.into(),
+                        selected: false,
+                    })
+                } else if path.extension().and_then(|ext| ext.to_str()) == Some("sql") {
+                    Some(ListEntry {
+                        is_directory: false,
+                        relative_path,
+                        name: file_name.into(),
+                        selected: false,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        entries.sort();
+        entries
+    }
+}
+
+/// Strips the `base_path` prefix off an absolute remote path, leaving a
+/// path relative to the repository root with no leading separator.
+///
+/// Only the leading occurrence is stripped (unlike `String::replace`,
+/// which would also corrupt separators elsewhere in the path), so this
+/// is correct even for the default `base_path` of `/`.
+fn strip_base_path(path: &str, base_path: &str) -> String {
+    path.strip_prefix(base_path)
+        .unwrap_or(path)
+        .trim_start_matches('/')
+        .to_owned()
+}
+
+impl ScriptSource for SftpRepository {
+    fn read_entries_in_current_directory(&self) -> Vec<ListEntry> {
+        self.list(&self.current_remote_path())
+    }
+
+    fn get_children(&self, path: String) -> Vec<String> {
+        let remote_dir = format!("{}/{path}", self.base_path);
+        self.list(&remote_dir)
+            .into_iter()
+            .filter(|e| !e.is_directory)
+            .map(|e| e.relative_path)
+            .collect()
+    }
+
+    fn open_directory(&mut self, directory_name: &str) {
+        self.path.push(directory_name.into());
+    }
+
+    fn leave_directory(&mut self) -> Option<String> {
+        self.path.pop()
+    }
+
+    fn read_files_after_in_directory(&self, from: &str) -> eyre::Result<Vec<String>> {
+        let sftp = self.sftp()?;
+        let entries = sftp.readdir(Path::new(&self.current_remote_path()))?;
+
+        let files = entries
+            .into_iter()
+            .filter_map(|(path, stat)| {
+                if stat.is_dir() {
+                    return None;
+                }
+                let file_name = path.file_name()?.to_str()?;
+                if file_name.starts_with('_') || file_name.starts_with('.') {
+                    return None;
+                }
+                if path.extension().and_then(|ext| ext.to_str()) != Some("sql") {
+                    return None;
+                }
+
+                let fixed = strip_base_path(path.to_str()?, &self.base_path);
+                Some((fixed, file_name.to_owned()))
+            })
+            .skip_while(|(_, name)| name != from)
+            .map(|(relative_path, _)| relative_path)
+            .collect();
+
+        Ok(files)
+    }
+
+    fn read_file(&self, relative_path: &str) -> eyre::Result<String> {
+        let sftp = self.sftp()?;
+        let remote_path = format!("{}/{relative_path}", self.base_path);
+        let mut file = sftp.open(Path::new(&remote_path))?;
+
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strips_only_leading_occurrence_of_default_base_path() {
+        assert_eq!(strip_base_path("/sub/file.sql", "/"), "sub/file.sql");
+    }
+
+    #[test]
+    fn strips_a_non_root_base_path() {
+        assert_eq!(
+            strip_base_path("/srv/scripts/sub/file.sql", "/srv/scripts"),
+            "sub/file.sql"
+        );
+    }
+
+    #[test]
+    fn does_not_corrupt_separators_that_match_base_path_elsewhere() {
+        // With the default base_path of "/", a naive `String::replace`
+        // would strip every separator in the path, not just the leading
+        // one, turning "/sub/file.sql" into "subfile.sql".
+        assert_eq!(strip_base_path("/a/b/c.sql", "/"), "a/b/c.sql");
+    }
+
+    #[test]
+    fn falls_back_to_the_original_path_when_base_path_does_not_match() {
+        assert_eq!(strip_base_path("foo/bar.sql", "/srv"), "foo/bar.sql");
+    }
+}